@@ -36,6 +36,7 @@ pub enum Type {
 #[derive(Clone, Copy, Deserialize, HeapSizeOf, PartialEq, Serialize)]
 pub enum Destination {
     None,
+    AudioWorklet,
     Document,
     Embed,
     Font,
@@ -43,6 +44,7 @@ pub enum Destination {
     Manifest,
     Media,
     Object,
+    PaintWorklet,
     Report,
     Script,
     ServiceWorker,
@@ -356,7 +358,8 @@ impl Request {
     /// https://fetch.spec.whatwg.org/#subresource-request
     pub fn is_subresource_request(&self) -> bool {
         match self.destination {
-            Destination::Font | Destination::Image | Destination::Manifest | Destination::Media |
+            Destination::AudioWorklet | Destination::Font | Destination::Image |
+            Destination::Manifest | Destination::Media | Destination::PaintWorklet |
             Destination::Script | Destination::Style | Destination::XSLT | Destination::None => true,
             _ => false,
         }
@@ -119,6 +119,13 @@ impl DocumentLoader {
         self.fetch_async_background(request, fetch_target);
     }
 
+    /// Register a load carried by an external transport instead of the
+    /// resource threads, still blocking the document load event. The
+    /// caller must balance it with `finish_load`.
+    pub fn add_transported_load(&mut self, load: LoadType) {
+        self.add_blocking_load(load);
+    }
+
     /// Initiate a new fetch that does not block the document load event.
     pub fn fetch_async_background(&self,
                                   request: RequestInit,
@@ -111,6 +111,7 @@ use net_traits::request::RequestInit;
 use net_traits::response::HttpsState;
 use num_traits::ToPrimitive;
 use script_layout_interface::message::{Msg, ReflowGoal};
+use script_module::{ModuleError, ModuleResult};
 use script_runtime::{CommonScriptMsg, ScriptThreadEventCategory};
 use script_thread::{MainThreadScriptMsg, ScriptThread};
 use script_traits::{AnimationState, CompositorEvent, DocumentActivity};
@@ -269,8 +270,15 @@ pub struct Document {
     deferred_scripts: PendingInOrderScriptVec,
     /// https://html.spec.whatwg.org/multipage/#list-of-scripts-that-will-execute-in-order-as-soon-as-possible
     asap_in_order_scripts_list: PendingInOrderScriptVec,
+    /// The counterpart of the in-order list for module scripts, whose
+    /// graphs may complete out of document order.
+    asap_in_order_module_scripts: PendingInOrderModuleVec,
     /// https://html.spec.whatwg.org/multipage/#set-of-scripts-that-will-execute-as-soon-as-possible
     asap_scripts_set: DomRefCell<Vec<Dom<HTMLScriptElement>>>,
+    /// https://html.spec.whatwg.org/multipage/#blocking-attribute
+    /// Module scripts with `blocking="render"` whose graphs have not
+    /// settled yet; rendering should not start while this is non-empty.
+    render_blocking_module_scripts: DomRefCell<Vec<Dom<HTMLScriptElement>>>,
     /// https://html.spec.whatwg.org/multipage/#concept-n-noscript
     /// True if scripting is enabled for all scripts in this document
     scripting_enabled: bool,
@@ -1799,6 +1807,43 @@ impl Document {
         self.asap_scripts_set.borrow_mut().push(Dom::from_ref(script));
     }
 
+    /// https://html.spec.whatwg.org/multipage/#blocking-attribute
+    /// Track a `blocking="render"` module script whose graph is in
+    /// flight. Idempotent per element, since a re-run of the same
+    /// element supersedes its previous load.
+    pub fn add_render_blocking_module_script(&self, script: &HTMLScriptElement) {
+        let mut scripts = self.render_blocking_module_scripts.borrow_mut();
+        if scripts.iter().any(|entry| &**entry == script) {
+            return;
+        }
+        scripts.push(Dom::from_ref(script));
+    }
+
+    /// Release a render-blocking module script once its graph settles,
+    /// successfully or not; a script that never registered is a no-op.
+    /// Releasing the last one issues the display reflow that
+    /// `Window::force_reflow` has been holding back.
+    pub fn remove_render_blocking_module_script(&self, script: &HTMLScriptElement) {
+        let unblocked = {
+            let mut scripts = self.render_blocking_module_scripts.borrow_mut();
+            match scripts.iter().position(|entry| &**entry == script) {
+                Some(idx) => {
+                    scripts.swap_remove(idx);
+                    scripts.is_empty()
+                },
+                None => false,
+            }
+        };
+        if unblocked {
+            self.window.reflow(ReflowGoal::Full, ReflowReason::ModuleGraphLoaded);
+        }
+    }
+
+    /// Whether rendering is currently blocked on module scripts.
+    pub fn render_blocked_by_module_scripts(&self) -> bool {
+        !self.render_blocking_module_scripts.borrow().is_empty()
+    }
+
     /// https://html.spec.whatwg.org/multipage/#the-end step 5.
     /// https://html.spec.whatwg.org/multipage/#prepare-a-script step 22.d.
     pub fn asap_script_loaded(&self, element: &HTMLScriptElement, result: ScriptResult) {
@@ -1826,6 +1871,36 @@ impl Document {
         }
     }
 
+    // https://html.spec.whatwg.org/multipage/#list-of-scripts-that-will-execute-in-order-as-soon-as-possible
+    pub fn push_asap_in_order_module_script(&self, script: &HTMLScriptElement) {
+        self.asap_in_order_module_scripts.push(script);
+    }
+
+    /// Whether `element` is queued as an in-order module script.
+    pub fn has_asap_in_order_module_script(&self, element: &HTMLScriptElement) -> bool {
+        self.asap_in_order_module_scripts.contains(element)
+    }
+
+    /// Buffer the completed graph of an in-order module script and fire
+    /// the events of every leading script whose result is ready, so load
+    /// and error events arrive in document order even when sibling graphs
+    /// complete out of order.
+    pub fn asap_in_order_module_script_loaded(&self,
+                                              element: &HTMLScriptElement,
+                                              result: ModuleResult) {
+        self.asap_in_order_module_scripts.loaded(element, result);
+        while let Some((element, result)) = self.asap_in_order_module_scripts.take_next_ready() {
+            match result {
+                Ok(()) => element.dispatch_load_event(),
+                // An aborted graph settles its slot in the queue — the
+                // scripts behind it must not wait forever — but fires
+                // neither load nor error.
+                Err(ModuleError::Aborted(_)) => {},
+                Err(_) => element.dispatch_error_event(),
+            }
+        }
+    }
+
     // https://html.spec.whatwg.org/multipage/#list-of-scripts-that-will-execute-when-the-document-has-finished-parsing
     pub fn add_deferred_script(&self, script: &HTMLScriptElement) {
         self.deferred_scripts.push(script);
@@ -2246,7 +2321,9 @@ impl Document {
             script_blocking_stylesheets_count: Cell::new(0u32),
             deferred_scripts: Default::default(),
             asap_in_order_scripts_list: Default::default(),
+            asap_in_order_module_scripts: Default::default(),
             asap_scripts_set: Default::default(),
+            render_blocking_module_scripts: Default::default(),
             scripting_enabled: has_browsing_context == HasBrowsingContext::Yes,
             animation_frame_ident: Cell::new(0),
             animation_frame_list: DomRefCell::new(vec![]),
@@ -4109,3 +4186,55 @@ impl PendingScript {
         self.load.take().map(|result| (DomRoot::from_ref(&*self.element), result))
     }
 }
+
+#[derive(Default, HeapSizeOf, JSTraceable)]
+#[must_root]
+struct PendingInOrderModuleVec {
+    scripts: DomRefCell<VecDeque<PendingModuleScript>>,
+}
+
+impl PendingInOrderModuleVec {
+    fn push(&self, element: &HTMLScriptElement) {
+        self.scripts.borrow_mut().push_back(PendingModuleScript {
+            element: Dom::from_ref(element),
+            load: None,
+        });
+    }
+
+    fn contains(&self, element: &HTMLScriptElement) -> bool {
+        self.scripts.borrow().iter().any(|entry| &*entry.element == element)
+    }
+
+    fn loaded(&self, element: &HTMLScriptElement, result: ModuleResult) {
+        let mut scripts = self.scripts.borrow_mut();
+        // An element that was never queued (or whose entry was already
+        // taken) has no slot to fill; its settlement simply isn't ours
+        // to order. Only the first settlement of a queued element
+        // counts.
+        if let Some(entry) = scripts.iter_mut().find(|entry| &*entry.element == element) {
+            if entry.load.is_none() {
+                entry.load = Some(result);
+            }
+        }
+    }
+
+    fn take_next_ready(&self) -> Option<(DomRoot<HTMLScriptElement>, ModuleResult)> {
+        let mut scripts = self.scripts.borrow_mut();
+        let pair = scripts.front_mut().and_then(|entry| {
+            entry.load.take().map(|result| (DomRoot::from_ref(&*entry.element), result))
+        });
+        if pair.is_none() {
+            return None;
+        }
+        scripts.pop_front();
+        pair
+    }
+}
+
+#[derive(HeapSizeOf, JSTraceable)]
+#[must_root]
+struct PendingModuleScript {
+    element: Dom<HTMLScriptElement>,
+    #[ignore_heap_size_of = "Defined in script_module"]
+    load: Option<ModuleResult>,
+}
@@ -37,6 +37,10 @@ use microtask::{Microtask, MicrotaskQueue};
 use msg::constellation_msg::PipelineId;
 use net_traits::{CoreResourceThread, ResourceThreads, IpcSend};
 use profile_traits::{mem, time};
+use script_module::{ImportMap, ImportMetaPopulator, ModuleEvaluationGate, ModuleFallbackProvider};
+use script_module::{ModuleProgressObserver, ModuleRequestDecorator, ModuleRequestHook};
+use script_module::{ModuleSourceTransformer, ModuleSpecifierResolver, ModuleTransport};
+use script_module::{ModuleTree, ModuleType, ModuleUrlRewriter, ScriptId};
 use script_runtime::{CommonScriptMsg, ScriptChan, ScriptPort};
 use script_thread::{MainThreadScriptChan, ScriptThread};
 use script_traits::{MsDuration, ScriptToConstellationChan, TimerEvent};
@@ -110,6 +114,100 @@ pub struct GlobalScope {
     /// https://html.spec.whatwg.org/multipage/#microtask-queue
     #[ignore_heap_size_of = "Rc<T> is hard"]
     microtask_queue: Rc<MicrotaskQueue>,
+
+    /// https://html.spec.whatwg.org/multipage/#module-map
+    #[ignore_heap_size_of = "Rc<T> is hard"]
+    module_map: DomRefCell<HashMap<ServoUrl, Rc<ModuleTree>>>,
+
+    /// The counterpart of the module map for inline module scripts, keyed
+    /// by the id of the script element that created each module.
+    #[ignore_heap_size_of = "Rc<T> is hard"]
+    inline_module_map: DomRefCell<HashMap<ScriptId, Rc<ModuleTree>>>,
+
+    /// Memoized module specifier resolutions, invalidated whenever the
+    /// import map changes.
+    module_resolution_cache: DomRefCell<HashMap<(ServoUrl, String), ServoUrl>>,
+
+    /// The specifiers requested by module sources seen so far, keyed by a
+    /// hash of the source text, so byte-identical modules served under
+    /// several URLs (e.g. CDN mirrors) skip re-walking their record.
+    module_compile_cache: DomRefCell<HashMap<u64, Vec<DOMString>>>,
+
+    /// https://html.spec.whatwg.org/multipage/#import-map
+    #[ignore_heap_size_of = "Defined in script_module"]
+    import_map: DomRefCell<Option<ImportMap>>,
+
+    /// Whether any module specifier has been resolved yet; an import map
+    /// arriving after that point is rejected.
+    module_resolution_started: Cell<bool>,
+
+    /// The integrity section of the import map: integrity metadata pinned
+    /// for module URLs, enforced on every fetch of those URLs.
+    module_integrity_map: DomRefCell<HashMap<ServoUrl, String>>,
+
+    /// An embedder hook rewriting the URL a module is fetched from, while
+    /// the logical URL stays the module map key.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_url_rewriter: DomRefCell<Option<Rc<ModuleUrlRewriter>>>,
+
+    /// An embedder hook observing, and possibly vetoing, each module
+    /// network request right before it is issued.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_request_hook: DomRefCell<Option<Rc<ModuleRequestHook>>>,
+
+    /// An embedder decorator contributing additive headers (client
+    /// hints, a module-specific UA) to every module request.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_request_decorator: DomRefCell<Option<Rc<ModuleRequestDecorator>>>,
+
+    /// An embedder populator adding properties to `import.meta` after
+    /// the standard ones.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    import_meta_populator: DomRefCell<Option<Rc<ImportMetaPopulator>>>,
+
+    /// An observer told about module fetch progress, for progress UIs.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_progress_observer: DomRefCell<Option<Rc<ModuleProgressObserver>>>,
+
+    /// An embedder resolver consulted before the built-in specifier
+    /// resolution, e.g. for conditional package exports.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_specifier_resolver: DomRefCell<Option<Rc<ModuleSpecifierResolver>>>,
+
+    /// An embedder provider of fallback source for failed module
+    /// fetches, e.g. polyfill stubs.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_fallback_provider: DomRefCell<Option<Rc<ModuleFallbackProvider>>>,
+
+    /// An instrumentation transform applied to module source before
+    /// compilation, e.g. for coverage tooling.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_source_transformer: DomRefCell<Option<Rc<ModuleSourceTransformer>>>,
+
+    /// An embedder transport carrying module requests instead of the
+    /// net stack, for hosts without one.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_transport: DomRefCell<Option<Rc<ModuleTransport>>>,
+
+    /// An embedder gate allowing, deferring or denying the evaluation
+    /// of a fetched and linked module graph.
+    #[ignore_heap_size_of = "trait objects are hard"]
+    module_evaluation_gate: DomRefCell<Option<Rc<ModuleEvaluationGate>>>,
+
+    /// Whether module fetches that would hit the network are forbidden in
+    /// this context; data:/blob:/inline module sources are still allowed.
+    network_module_fetches_disabled: Cell<bool>,
+
+    /// Whether this global is a privileged (chrome or extension) context
+    /// allowed to load extension-scheme modules; never set for ordinary
+    /// web content.
+    privileged_module_schemes: Cell<bool>,
+
+    /// Legacy compat: whether a bareword module specifier that resolves
+    /// to nothing (`import "foo"` with no import map entry) is retried
+    /// as `./foo` instead of being rejected; off by default, which is
+    /// the spec behavior.
+    bareword_specifiers_relative: Cell<bool>,
 }
 
 impl GlobalScope {
@@ -142,9 +240,219 @@ impl GlobalScope {
             timers: OneshotTimers::new(timer_event_chan, scheduler_chan),
             origin,
             microtask_queue,
+            module_map: DomRefCell::new(Default::default()),
+            inline_module_map: DomRefCell::new(Default::default()),
+            module_resolution_cache: DomRefCell::new(Default::default()),
+            module_compile_cache: DomRefCell::new(Default::default()),
+            import_map: DomRefCell::new(None),
+            module_resolution_started: Cell::new(false),
+            module_integrity_map: DomRefCell::new(Default::default()),
+            module_url_rewriter: DomRefCell::new(None),
+            module_request_hook: DomRefCell::new(None),
+            module_request_decorator: DomRefCell::new(None),
+            import_meta_populator: DomRefCell::new(None),
+            module_progress_observer: DomRefCell::new(None),
+            module_specifier_resolver: DomRefCell::new(None),
+            module_fallback_provider: DomRefCell::new(None),
+            module_source_transformer: DomRefCell::new(None),
+            module_transport: DomRefCell::new(None),
+            module_evaluation_gate: DomRefCell::new(None),
+            network_module_fetches_disabled: Cell::new(false),
+            privileged_module_schemes: Cell::new(false),
+            bareword_specifiers_relative: Cell::new(false),
         }
     }
 
+    pub fn get_module_map(&self) -> &DomRefCell<HashMap<ServoUrl, Rc<ModuleTree>>> {
+        &self.module_map
+    }
+
+    pub fn set_module_map(&self, url: ServoUrl, module: Rc<ModuleTree>) {
+        self.module_map.borrow_mut().insert(url, module);
+    }
+
+    /// The number of external modules currently held by the module map,
+    /// for leak detection: after teardown or eviction the count should
+    /// return to its baseline.
+    pub fn module_map_len(&self) -> usize {
+        self.module_map.borrow().len()
+    }
+
+    /// The inline counterpart of `module_map_len`.
+    pub fn inline_module_map_len(&self) -> usize {
+        self.inline_module_map.borrow().len()
+    }
+
+    /// Whether `url` has an entry in the module map, regardless of how
+    /// far along its fetch is.
+    pub fn is_module_loaded(&self, url: &ServoUrl) -> bool {
+        self.module_map.borrow().contains_key(url)
+    }
+
+    /// The kind of module `url` is loaded as, if the module map knows
+    /// the URL at all. The type of a tree that has not received its
+    /// response yet is still the `JavaScript` default.
+    pub fn module_type_of(&self, url: &ServoUrl) -> Option<ModuleType> {
+        self.module_map.borrow().get(url).map(|module| module.get_module_type())
+    }
+
+    pub fn get_inline_module_map(&self) -> &DomRefCell<HashMap<ScriptId, Rc<ModuleTree>>> {
+        &self.inline_module_map
+    }
+
+    /// Register the module tree of an inline script. A re-run of the
+    /// same element (e.g. one cloned and reinserted) reuses its id and
+    /// replaces the previous tree, which is returned so the caller can
+    /// detach it.
+    pub fn set_inline_module_map(&self, script_id: ScriptId, module: Rc<ModuleTree>)
+                                 -> Option<Rc<ModuleTree>> {
+        self.inline_module_map.borrow_mut().insert(script_id, module)
+    }
+
+    pub fn get_module_resolution_cache(&self) -> &DomRefCell<HashMap<(ServoUrl, String), ServoUrl>> {
+        &self.module_resolution_cache
+    }
+
+    /// Drop every memoized specifier resolution; called whenever the
+    /// import map changes, since cached results may resolve differently.
+    pub fn clear_module_resolution_cache(&self) {
+        self.module_resolution_cache.borrow_mut().clear();
+    }
+
+    pub fn get_module_compile_cache(&self) -> &DomRefCell<HashMap<u64, Vec<DOMString>>> {
+        &self.module_compile_cache
+    }
+
+    pub fn get_import_map(&self) -> &DomRefCell<Option<ImportMap>> {
+        &self.import_map
+    }
+
+    pub fn module_resolution_started(&self) -> bool {
+        self.module_resolution_started.get()
+    }
+
+    pub fn note_module_resolution_started(&self) {
+        self.module_resolution_started.set(true);
+    }
+
+    pub fn get_module_integrity_map(&self) -> &DomRefCell<HashMap<ServoUrl, String>> {
+        &self.module_integrity_map
+    }
+
+    pub fn set_module_integrity(&self, url: ServoUrl, integrity_metadata: String) {
+        self.module_integrity_map.borrow_mut().insert(url, integrity_metadata);
+    }
+
+    pub fn get_module_url_rewriter(&self) -> &DomRefCell<Option<Rc<ModuleUrlRewriter>>> {
+        &self.module_url_rewriter
+    }
+
+    pub fn set_module_url_rewriter(&self, rewriter: Option<Rc<ModuleUrlRewriter>>) {
+        *self.module_url_rewriter.borrow_mut() = rewriter;
+    }
+
+    pub fn get_module_request_hook(&self) -> &DomRefCell<Option<Rc<ModuleRequestHook>>> {
+        &self.module_request_hook
+    }
+
+    pub fn set_module_request_hook(&self, hook: Option<Rc<ModuleRequestHook>>) {
+        *self.module_request_hook.borrow_mut() = hook;
+    }
+
+    pub fn get_module_request_decorator(&self) -> &DomRefCell<Option<Rc<ModuleRequestDecorator>>> {
+        &self.module_request_decorator
+    }
+
+    pub fn set_module_request_decorator(&self, decorator: Option<Rc<ModuleRequestDecorator>>) {
+        *self.module_request_decorator.borrow_mut() = decorator;
+    }
+
+    pub fn get_import_meta_populator(&self) -> &DomRefCell<Option<Rc<ImportMetaPopulator>>> {
+        &self.import_meta_populator
+    }
+
+    pub fn set_import_meta_populator(&self, populator: Option<Rc<ImportMetaPopulator>>) {
+        *self.import_meta_populator.borrow_mut() = populator;
+    }
+
+    pub fn get_module_progress_observer(&self) -> &DomRefCell<Option<Rc<ModuleProgressObserver>>> {
+        &self.module_progress_observer
+    }
+
+    pub fn set_module_progress_observer(&self, observer: Option<Rc<ModuleProgressObserver>>) {
+        *self.module_progress_observer.borrow_mut() = observer;
+    }
+
+    pub fn get_module_specifier_resolver(&self) -> &DomRefCell<Option<Rc<ModuleSpecifierResolver>>> {
+        &self.module_specifier_resolver
+    }
+
+    /// Install an embedder specifier resolver; resolutions memoized
+    /// before this point would bypass it, so the cache is dropped.
+    pub fn set_module_specifier_resolver(&self, resolver: Option<Rc<ModuleSpecifierResolver>>) {
+        *self.module_specifier_resolver.borrow_mut() = resolver;
+        self.clear_module_resolution_cache();
+    }
+
+    pub fn get_module_fallback_provider(&self) -> &DomRefCell<Option<Rc<ModuleFallbackProvider>>> {
+        &self.module_fallback_provider
+    }
+
+    pub fn set_module_fallback_provider(&self, provider: Option<Rc<ModuleFallbackProvider>>) {
+        *self.module_fallback_provider.borrow_mut() = provider;
+    }
+
+    pub fn get_module_source_transformer(&self) -> &DomRefCell<Option<Rc<ModuleSourceTransformer>>> {
+        &self.module_source_transformer
+    }
+
+    pub fn set_module_source_transformer(&self, transformer: Option<Rc<ModuleSourceTransformer>>) {
+        *self.module_source_transformer.borrow_mut() = transformer;
+    }
+
+    pub fn get_module_transport(&self) -> &DomRefCell<Option<Rc<ModuleTransport>>> {
+        &self.module_transport
+    }
+
+    pub fn set_module_transport(&self, transport: Option<Rc<ModuleTransport>>) {
+        *self.module_transport.borrow_mut() = transport;
+    }
+
+    pub fn get_module_evaluation_gate(&self) -> &DomRefCell<Option<Rc<ModuleEvaluationGate>>> {
+        &self.module_evaluation_gate
+    }
+
+    pub fn set_module_evaluation_gate(&self, gate: Option<Rc<ModuleEvaluationGate>>) {
+        *self.module_evaluation_gate.borrow_mut() = gate;
+    }
+
+    pub fn network_module_fetches_disabled(&self) -> bool {
+        self.network_module_fetches_disabled.get()
+    }
+
+    pub fn set_network_module_fetches_disabled(&self, disabled: bool) {
+        self.network_module_fetches_disabled.set(disabled);
+    }
+
+    pub fn privileged_module_schemes_enabled(&self) -> bool {
+        self.privileged_module_schemes.get()
+    }
+
+    /// Grant (or revoke) the privileged-scheme module capability; only
+    /// an embedder setting up a chrome or extension global should call
+    /// this.
+    pub fn set_privileged_module_schemes_enabled(&self, enabled: bool) {
+        self.privileged_module_schemes.set(enabled);
+    }
+
+    pub fn bareword_specifiers_relative(&self) -> bool {
+        self.bareword_specifiers_relative.get()
+    }
+
+    pub fn set_bareword_specifiers_relative(&self, enabled: bool) {
+        self.bareword_specifiers_relative.set(enabled);
+    }
+
     /// Returns the global scope of the realm that the given DOM object's reflector
     /// was created in.
     #[allow(unsafe_code)]
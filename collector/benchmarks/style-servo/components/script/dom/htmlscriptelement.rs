@@ -32,6 +32,8 @@ use js::jsval::UndefinedValue;
 use net_traits::{FetchMetadata, FetchResponseListener, Metadata, NetworkError};
 use net_traits::request::{CorsSettings, CredentialsMode, Destination, RequestInit, RequestMode, Type as RequestType};
 use network_listener::{NetworkListener, PreInvoke};
+use script_module::{ModuleOwner, ScriptId};
+use script_module::{fetch_external_module_script, fetch_inline_module_script};
 use servo_atoms::Atom;
 use servo_config::opts;
 use servo_url::ServoUrl;
@@ -65,6 +67,10 @@ pub struct HTMLScriptElement {
 
     /// Track line line_number
     line_number: u64,
+
+    /// Unique id for each script element
+    #[ignore_heap_size_of = "Defined in uuid"]
+    id: ScriptId,
 }
 
 impl HTMLScriptElement {
@@ -78,9 +84,14 @@ impl HTMLScriptElement {
             non_blocking: Cell::new(!creator.is_parser_created()),
             parser_document: Dom::from_ref(document),
             line_number: creator.return_line_number(),
+            id: ScriptId::new(),
         }
     }
 
+    pub fn get_script_id(&self) -> ScriptId {
+        self.id.clone()
+    }
+
     #[allow(unrooted_must_root)]
     pub fn new(local_name: LocalName, prefix: Option<Prefix>, document: &Document,
                creator: ElementCreator) -> DomRoot<HTMLScriptElement> {
@@ -93,7 +104,7 @@ impl HTMLScriptElement {
 
 /// Supported script types as defined by
 /// <https://html.spec.whatwg.org/multipage/#javascript-mime-type>.
-static SCRIPT_JS_MIMES: StaticStringVec = &[
+pub static SCRIPT_JS_MIMES: StaticStringVec = &[
     "application/ecmascript",
     "application/javascript",
     "application/x-ecmascript",
@@ -316,8 +327,9 @@ impl HTMLScriptElement {
             return;
         }
 
-        // Step 6.
-        if !self.is_javascript() {
+        // Step 6: the script is a classic script or a module script.
+        let is_module = self.is_module_type();
+        if !is_module && !self.is_javascript() {
             return;
         }
 
@@ -414,6 +426,28 @@ impl HTMLScriptElement {
                 },
             };
 
+            // Step 21.6: a module script graph fetches, compiles and
+            // runs through the module machinery, which owns its own
+            // queueing and event dispatch; the classic queues below do
+            // not apply to it.
+            if is_module {
+                // A module script without `async` joins the in-order
+                // module list, so its load and error events fire in
+                // document order even when sibling graphs settle out of
+                // order.
+                if !async {
+                    doc.push_asap_in_order_module_script(self);
+                }
+                fetch_external_module_script(
+                    ModuleOwner::Window(Trusted::new(self)),
+                    url,
+                    Destination::Script,
+                    cors_setting,
+                    if integrity_metadata.is_empty() { None } else { Some(integrity_metadata.to_owned()) },
+                    None);
+                return;
+            }
+
             // Preparation for step 23.
             let kind = if element.has_attribute(&local_name!("defer")) && was_parser_inserted && !async {
                 // Step 23.a: classic, has src, has defer, was parser-inserted, is not async.
@@ -442,6 +476,24 @@ impl HTMLScriptElement {
         } else {
             // Step 22.
             assert!(!text.is_empty());
+
+            if is_module {
+                // Inline modules respect the in-order list just like
+                // external ones: without this, an inline non-async
+                // module could fire load before an earlier in-order
+                // external sibling.
+                if !async {
+                    doc.push_asap_in_order_module_script(self);
+                }
+                fetch_inline_module_script(
+                    ModuleOwner::Window(Trusted::new(self)),
+                    text,
+                    base_url,
+                    self.id.clone(),
+                    cors_setting);
+                return;
+            }
+
             let result = Ok(ClassicScript::internal(text, base_url));
 
             // Step 23.
@@ -630,10 +682,37 @@ impl HTMLScriptElement {
         is_js
     }
 
+    /// https://html.spec.whatwg.org/multipage/#prepare-a-script step 7:
+    /// whether the type attribute says "module", matched ASCII
+    /// case-insensitively.
+    pub fn is_module_type(&self) -> bool {
+        self.upcast::<Element>()
+            .get_attribute(&ns!(), &local_name!("type"))
+            .map_or(false, |attr| {
+                attr.value().trim_matches(HTML_SPACE_CHARACTERS).eq_ignore_ascii_case("module")
+            })
+    }
+
     pub fn set_parser_inserted(&self, parser_inserted: bool) {
         self.parser_inserted.set(parser_inserted);
     }
 
+    pub fn is_non_blocking(&self) -> bool {
+        self.non_blocking.get()
+    }
+
+    /// https://html.spec.whatwg.org/multipage/#blocking-attribute
+    /// Whether this script declares itself render-blocking with
+    /// `blocking="render"`. The token is matched ASCII
+    /// case-insensitively; unknown tokens are ignored.
+    pub fn is_render_blocking(&self) -> bool {
+        self.upcast::<Element>()
+            .get_attribute_by_name(DOMString::from("blocking"))
+            .map_or(false, |attr| {
+                attr.value().split_whitespace().any(|token| token.eq_ignore_ascii_case("render"))
+            })
+    }
+
     pub fn set_already_started(&self, already_started: bool) {
         self.already_started.set(already_started);
     }
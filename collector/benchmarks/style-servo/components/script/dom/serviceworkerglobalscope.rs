@@ -25,6 +25,7 @@ use js::jsval::UndefinedValue;
 use js::rust::Runtime;
 use net_traits::{load_whole_resource, IpcSend, CustomResponseMediator};
 use net_traits::request::{CredentialsMode, Destination, RequestInit, Type as RequestType};
+use script_module::load_service_worker_module_graph;
 use script_runtime::{CommonScriptMsg, ScriptChan, new_rt_and_cx};
 use script_traits::{TimerEvent, WorkerGlobalScopeInit, ScopeThings, ServiceWorkerMsg, WorkerScriptLoadOrigin};
 use servo_config::prefs::PREFS;
@@ -147,6 +148,7 @@ impl ServiceWorkerGlobalScope {
         let ScopeThings { script_url,
                           init,
                           worker_load_origin,
+                          is_module_script,
                           .. } = scope_things;
 
         let serialized_worker_url = script_url.to_string();
@@ -158,27 +160,35 @@ impl ServiceWorkerGlobalScope {
 
             let WorkerScriptLoadOrigin { referrer_url, referrer_policy, pipeline_id } = worker_load_origin;
 
-            let request = RequestInit {
-                url: script_url.clone(),
-                type_: RequestType::Script,
-                destination: Destination::ServiceWorker,
-                credentials_mode: CredentialsMode::Include,
-                use_url_credentials: true,
-                pipeline_id: pipeline_id,
-                referrer_url: referrer_url,
-                referrer_policy: referrer_policy,
-                origin,
-                .. RequestInit::default()
-            };
+            let resource_threads_sender = init.resource_threads.sender();
 
-            let (url, source) = match load_whole_resource(request,
-                                                          &init.resource_threads.sender()) {
-                Err(_) => {
-                    println!("error loading script {}", serialized_worker_url);
-                    return;
-                }
-                Ok((metadata, bytes)) => {
-                    (metadata.final_url, String::from_utf8(bytes).unwrap())
+            // A module worker fetches its graph against the worker
+            // global below, so only the classic path loads source here.
+            let (url, source) = if is_module_script {
+                (script_url.clone(), String::new())
+            } else {
+                let request = RequestInit {
+                    url: script_url.clone(),
+                    type_: RequestType::Script,
+                    destination: Destination::ServiceWorker,
+                    credentials_mode: CredentialsMode::Include,
+                    use_url_credentials: true,
+                    pipeline_id: pipeline_id,
+                    referrer_url: referrer_url,
+                    referrer_policy: referrer_policy,
+                    origin,
+                    .. RequestInit::default()
+                };
+                match load_whole_resource(request, &resource_threads_sender) {
+                    Err(_) => {
+                        println!("error loading script {}", serialized_worker_url);
+                        let _ = swmanager_sender.send(
+                            ServiceWorkerMsg::RegistrationFailed(scope_url));
+                        return;
+                    }
+                    Ok((metadata, bytes)) => {
+                        (metadata.final_url, String::from_utf8(bytes).unwrap())
+                    }
                 }
             };
 
@@ -192,7 +202,7 @@ impl ServiceWorkerGlobalScope {
             let global = ServiceWorkerGlobalScope::new(
                 init, url, devtools_mpsc_port, runtime,
                 own_sender, receiver,
-                timer_ipc_chan, timer_port, swmanager_sender, scope_url);
+                timer_ipc_chan, timer_port, swmanager_sender.clone(), scope_url.clone());
             let scope = global.upcast::<WorkerGlobalScope>();
 
             unsafe {
@@ -200,7 +210,23 @@ impl ServiceWorkerGlobalScope {
                 JS_SetInterruptCallback(scope.runtime(), Some(interrupt_callback));
             }
 
-            scope.execute_script(DOMString::from(source));
+            if is_module_script {
+                // An errored module graph must fail the registration
+                // rather than leave a worker that silently never ran:
+                // the manager drops the scope and this thread exits
+                // before activation.
+                if let Err(error) = load_service_worker_module_graph(scope.upcast::<GlobalScope>(),
+                                                                     script_url.clone(),
+                                                                     &resource_threads_sender) {
+                    warn!("service worker module graph of {} failed to load: {:?}",
+                          serialized_worker_url, error);
+                    let _ = swmanager_sender.send(
+                        ServiceWorkerMsg::RegistrationFailed(scope_url));
+                    return;
+                }
+            } else {
+                scope.execute_script(DOMString::from(source));
+            }
             // Service workers are time limited
             thread::Builder::new().name("SWTimeoutThread".to_owned()).spawn(move || {
                 let sw_lifetime_timeout = PREFS.get("dom.serviceworker.timeout_seconds").as_u64().unwrap();
@@ -74,7 +74,12 @@ impl ServiceWorkerRegistration {
             init: init,
             worker_load_origin: worker_load_origin,
             devtools_chan: devtools_chan,
-            worker_id: worker_id
+            worker_id: worker_id,
+            // `RegistrationOptions` does not surface `{type: "module"}`
+            // yet, so a registration from content is always a classic
+            // script; an embedder constructing its own ScopeThings can
+            // ask for a module graph.
+            is_module_script: false,
         }
     }
 
@@ -152,6 +152,7 @@ pub enum ReflowReason {
     RequestAnimationFrame,
     WebFontLoaded,
     WorkletLoaded,
+    ModuleGraphLoaded,
     FramedContentChanged,
     IFrameLoadEvent,
     MissingExplicitReflow,
@@ -1220,6 +1221,15 @@ impl Window {
             return false;
         }
 
+        // A `blocking="render"` module script holds back display reflows
+        // until its graph settles; `Document::remove_render_blocking_module_script`
+        // issues the catch-up reflow once the last one does.
+        if for_display && self.Document().render_blocked_by_module_scripts() {
+            debug!("Suppressing reflow pipeline {} for reason {:?}: render-blocking module scripts in flight",
+                   self.upcast::<GlobalScope>().pipeline_id(), reason);
+            return false;
+        }
+
         debug!("script: performing reflow for reason {:?}", reason);
 
         let marker = if self.need_emit_timeline_marker(TimelineMarkerType::Reflow) {
@@ -1338,7 +1348,8 @@ impl Window {
             assert!(!self.Document().needs_reflow() ||
                     (!for_display && self.Document().needs_paint()) ||
                     self.window_size.get().is_none() ||
-                    self.suppress_reflow.get());
+                    self.suppress_reflow.get() ||
+                    self.Document().render_blocked_by_module_scripts());
         } else {
             debug!("Document doesn't need reflow - skipping it (reason {:?})", reason);
         }
@@ -1930,6 +1941,7 @@ fn debug_reflow_events(id: PipelineId, reflow_goal: &ReflowGoal, reason: &Reflow
         ReflowReason::RequestAnimationFrame => "\tRequestAnimationFrame",
         ReflowReason::WebFontLoaded => "\tWebFontLoaded",
         ReflowReason::WorkletLoaded => "\tWorkletLoaded",
+        ReflowReason::ModuleGraphLoaded => "\tModuleGraphLoaded",
         ReflowReason::FramedContentChanged => "\tFramedContentChanged",
         ReflowReason::IFrameLoadEvent => "\tIFrameLoadEvent",
         ReflowReason::MissingExplicitReflow => "\tMissingExplicitReflow",
@@ -556,6 +556,7 @@ impl WorkletThread {
     fn fetch_and_invoke_a_worklet_script(&self,
                                          global_scope: &WorkletGlobalScope,
                                          pipeline_id: PipelineId,
+                                         destination: Destination,
                                          origin: ImmutableOrigin,
                                          script_url: ServoUrl,
                                          credentials: RequestCredentials,
@@ -574,7 +575,7 @@ impl WorkletThread {
         let request = RequestInit {
             url: script_url,
             type_: RequestType::Script,
-            destination: Destination::Script,
+            destination: destination,
             mode: RequestMode::CorsMode,
             credentials_mode: credentials.into(),
             origin,
@@ -632,6 +633,7 @@ impl WorkletThread {
                                                            base_url);
                 self.fetch_and_invoke_a_worklet_script(&*global,
                                                        pipeline_id,
+                                                       global_type.destination(),
                                                        origin,
                                                        script_url,
                                                        credentials,
@@ -20,6 +20,7 @@ use js::rust::Runtime;
 use msg::constellation_msg::PipelineId;
 use net_traits::ResourceThreads;
 use net_traits::image_cache::ImageCache;
+use net_traits::request::Destination;
 use profile_traits::mem;
 use profile_traits::time;
 use script_thread::MainThreadScriptMsg;
@@ -180,6 +181,16 @@ impl WorkletGlobalScopeType {
                 DomRoot::upcast(PaintWorkletGlobalScope::new(runtime, pipeline_id, base_url, executor, init)),
         }
     }
+
+    /// The request destination of this type of worklet's script fetches.
+    /// The fetch spec has no destination for the testing worklet; a
+    /// plain script is the nearest thing.
+    pub fn destination(&self) -> Destination {
+        match *self {
+            WorkletGlobalScopeType::Test => Destination::Script,
+            WorkletGlobalScopeType::Paint => Destination::PaintWorklet,
+        }
+    }
 }
 
 /// A task which can be performed in the context of a worklet global.
@@ -84,6 +84,7 @@ extern crate script_layout_interface;
 extern crate script_traits;
 extern crate selectors;
 extern crate serde;
+extern crate serde_json;
 extern crate servo_arc;
 #[macro_use] extern crate servo_atoms;
 extern crate servo_config;
@@ -120,6 +121,7 @@ mod layout_image;
 mod mem;
 mod microtask;
 mod network_listener;
+pub mod script_module;
 pub mod script_runtime;
 #[allow(unsafe_code)]
 pub mod script_thread;
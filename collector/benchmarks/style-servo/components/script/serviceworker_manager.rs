@@ -139,6 +139,14 @@ impl ServiceWorkerManager {
                 }
                 true
             }
+            ServiceWorkerMsg::RegistrationFailed(scope) => {
+                // The worker's script (or module graph) never became
+                // runnable; keeping the descriptor around would just
+                // respawn the same failure on the next matching fetch.
+                let _ = self.registered_workers.remove(&scope);
+                let _ = self.active_workers.remove(&scope);
+                true
+            }
             ServiceWorkerMsg::Timeout(scope) => {
                 if self.active_workers.contains_key(&scope) {
                     let _ = self.active_workers.remove(&scope);
@@ -177,6 +177,9 @@ pub struct ScopeThings {
     pub devtools_chan: Option<IpcSender<ScriptToDevtoolsControlMsg>>,
     /// service worker id
     pub worker_id: WorkerId,
+    /// whether the main script is loaded as a module graph rather than
+    /// a classic script
+    pub is_module_script: bool,
 }
 
 /// Message that gets passed to service worker scope on postMessage
@@ -198,6 +201,9 @@ pub enum ServiceWorkerMsg {
     RegisterServiceWorker(ScopeThings, ServoUrl),
     /// Timeout message sent by active service workers
     Timeout(ServoUrl),
+    /// The worker's main script (or module graph) failed to load or
+    /// evaluate, so the registration for the scope must be dropped
+    RegistrationFailed(ServoUrl),
     /// Message sent by constellation to forward to a running service worker
     ForwardDOMMessage(DOMMessage, ServoUrl),
     /// Exit the service worker manager